@@ -0,0 +1,284 @@
+//! This module contains `BluesteinEvaluationDomain`, an `EvaluationDomain`
+//! that performs a DFT over *exactly* `n` points for an `n` that the
+//! power-of-two and mixed-radix domains can't reach directly, using only
+//! the existing radix-2 FFT machinery.
+//!
+//! The construction is Bluestein's chirp-z transform: given a primitive
+//! `n`-th root of unity `ω` and a `2n`-th root `ν` with `ν^2 = ω`, and
+//! writing `jk = (j^2 + k^2 - (k - j)^2) / 2`,
+//! `X_k = Σ_j x_j ω^{jk} = ν^{k^2} · Σ_j (x_j ν^{j^2}) · ν^{-(k - j)^2}`.
+//! Setting `a_j = x_j ν^{j^2}` and `b_m = ν^{-m^2}` for `m ∈ [-(n-1), n-1]`,
+//! the inner sum is the linear convolution of `a` and `b`, which is
+//! performed as a circular convolution over a power-of-two length
+//! `M ≥ 2n - 1` via the crate's ordinary radix-2 (I)FFTs: zero-pad and
+//! wrap `a` and `b` to length `M`, multiply their forward transforms
+//! pointwise, and inverse-transform. Since `b` does not depend on the
+//! input, its padded forward transform is computed once, at construction.
+
+use crate::domain::{utils::Elements, DomainCoeff, EvaluationDomain, Radix2EvaluationDomain};
+use ark_ff::FftField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, vec::Vec};
+
+/// The input-independent tables needed to run a size-`n` Bluestein
+/// transform in one direction (forward or inverse): the inverse chirp used
+/// to form `a_j` (and to rescale the output), the padded convolution
+/// domain, and the precomputed forward transform of the chirp `b`.
+#[derive(Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+struct ChirpTables<F: FftField> {
+    /// `ν^{-j^2}` for `j` in `0..n`.
+    chirp_inv: Vec<F>,
+    /// The power-of-two domain of size `M >= 2n - 1` used to convolve `a`
+    /// and `b` via the crate's ordinary radix-2 FFTs.
+    conv_domain: Radix2EvaluationDomain<F>,
+    /// The forward transform, over `conv_domain`, of the zero-padded,
+    /// wrapped chirp `b`.
+    b_fft: Vec<F>,
+}
+
+impl<F: FftField> ChirpTables<F> {
+    fn build(n: usize, nu: F) -> Option<Self> {
+        let conv_size = (2 * n - 1).next_power_of_two();
+        let conv_domain = Radix2EvaluationDomain::new(conv_size)?;
+
+        let nu_inv = nu.inverse()?;
+        let mut chirp = Vec::with_capacity(n);
+        let mut chirp_inv = Vec::with_capacity(n);
+        for j in 0..n {
+            let j2 = (j as u64) * (j as u64);
+            chirp.push(nu.pow([j2]));
+            chirp_inv.push(nu_inv.pow([j2]));
+        }
+
+        // `b` is the length-`conv_size` wraparound of `ν^{m^2}` for
+        // `m ∈ [-(n-1), n-1]`: `b[0] = ν^0` and, for `m` in `1..n`,
+        // `b[m] = b[conv_size - m] = ν^{m^2}` (since `(-m)^2 = m^2`).
+        let mut b = vec![F::zero(); conv_size];
+        b[0] = chirp[0];
+        for m in 1..n {
+            b[m] = chirp[m];
+            b[conv_size - m] = chirp[m];
+        }
+        let b_fft = conv_domain.fft(&b);
+
+        Some(Self {
+            chirp_inv,
+            conv_domain,
+            b_fft,
+        })
+    }
+
+    /// Run the size-`n` transform on `coeffs` via the chirp-z convolution.
+    fn transform<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let n = coeffs.len();
+        let mut a = vec![T::zero(); self.conv_domain.size()];
+        for ((a_j, x_j), chirp_inv_j) in a.iter_mut().zip(coeffs).zip(&self.chirp_inv) {
+            let mut val = *x_j;
+            val *= *chirp_inv_j;
+            *a_j = val;
+        }
+
+        self.conv_domain.fft_in_place(&mut a);
+        for (a_i, b_i) in a.iter_mut().zip(&self.b_fft) {
+            *a_i *= *b_i;
+        }
+        self.conv_domain.ifft_in_place(&mut a);
+
+        a.truncate(n);
+        for (out_k, chirp_inv_k) in a.iter_mut().zip(&self.chirp_inv) {
+            *out_k *= *chirp_inv_k;
+        }
+        a
+    }
+}
+
+/// An `EvaluationDomain` that performs a DFT over exactly `n` points, for
+/// `n` that need not be a power of two or factor into small primes, as
+/// long as an `n`-th and a `2n`-th root of unity exist in `F`.
+#[derive(Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BluesteinEvaluationDomain<F: FftField> {
+    size: usize,
+    size_as_field_element: F,
+    size_inv: F,
+    group_gen: F,
+    group_gen_inv: F,
+    offset: F,
+    offset_inv: F,
+    offset_pow_size: F,
+    forward: ChirpTables<F>,
+    inverse: ChirpTables<F>,
+}
+
+impl<F: FftField> fmt::Debug for BluesteinEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bluestein multiplicative subgroup of size {}", self.size)
+    }
+}
+
+impl<F: FftField> EvaluationDomain<F> for BluesteinEvaluationDomain<F> {
+    type Elements = Elements<F, Self>;
+
+    fn new(num_coeffs: usize) -> Option<Self> {
+        if num_coeffs == 0 {
+            return None;
+        }
+        let size_u64 = num_coeffs as u64;
+        let group_gen = F::get_root_of_unity(size_u64)?;
+        debug_assert_eq!(group_gen.pow([size_u64]), F::one());
+        let nu = F::get_root_of_unity(2 * size_u64)?;
+        debug_assert_eq!(nu * nu, group_gen);
+
+        // `ChirpTables::build(n, v)` computes `Σ_j x_j · (v^2)^{-jk}` (see
+        // the derivation in the module docs), so to get the forward
+        // transform `Σ_j x_j · group_gen^{+jk}` we must feed it `ν^{-1}`
+        // (whose square is `group_gen^{-1}`); the plain `ν` gives the
+        // inverse-direction sum `ifft_in_place` needs.
+        let forward = ChirpTables::build(num_coeffs, nu.inverse()?)?;
+        let inverse = ChirpTables::build(num_coeffs, nu)?;
+
+        let size_as_field_element = F::from(size_u64);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(Self {
+            size: num_coeffs,
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+            forward,
+            inverse,
+        })
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(Self {
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size as u64]),
+            ..self.clone()
+        })
+    }
+
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        F::get_root_of_unity(num_coeffs as u64)?;
+        F::get_root_of_unity(2 * num_coeffs as u64)?;
+        Some(num_coeffs)
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn log_size_of_group(&self) -> u64 {
+        self.size.next_power_of_two().trailing_zeros() as u64
+    }
+
+    fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    fn group_gen_inv(&self) -> F {
+        self.group_gen_inv
+    }
+
+    fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    fn coset_offset_inv(&self) -> F {
+        self.offset_inv
+    }
+
+    fn coset_offset_pow_size(&self) -> F {
+        self.offset_pow_size
+    }
+
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        coeffs.resize(self.size, T::zero());
+        if !self.offset.is_one() {
+            Self::distribute_powers(coeffs, self.offset);
+        }
+        *coeffs = self.forward.transform(&coeffs[..]);
+    }
+
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size, T::zero());
+        let mut result = self.inverse.transform(&evals[..]);
+        result.iter_mut().for_each(|val| *val *= self.size_inv);
+        if !self.offset_inv.is_one() {
+            Self::distribute_powers(&mut result, self.offset_inv);
+        }
+        *evals = result;
+    }
+
+    fn elements(&self) -> Elements<F, Self> {
+        Elements {
+            cur_elem: self.coset_offset(),
+            cur_pow: 0,
+            domain: self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Radix2EvaluationDomain;
+    use ark_ff::{One, Zero};
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    // `n` is a power of two so that `Radix2EvaluationDomain` can act as a
+    // ground truth for the same domain size and `group_gen`.
+    #[test]
+    fn fft_matches_radix2_for_a_shared_size() {
+        let n = 16;
+        let radix2 = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+        let bluestein = BluesteinEvaluationDomain::<Fr>::new(n).unwrap();
+        assert_eq!(radix2.group_gen(), bluestein.group_gen());
+
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+        assert_eq!(radix2.fft(&coeffs), bluestein.fft(&coeffs));
+        assert_eq!(radix2.ifft(&coeffs), bluestein.ifft(&coeffs));
+    }
+
+    #[test]
+    fn ifft_of_fft_is_identity() {
+        let n = 11;
+        let domain = BluesteinEvaluationDomain::<Fr>::new(n).unwrap();
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+        let evals = domain.fft(&coeffs);
+        assert_eq!(domain.ifft(&evals), coeffs);
+    }
+
+    #[test]
+    fn fft_matches_naive_dft_at_domain_elements() {
+        let n = 11;
+        let domain = BluesteinEvaluationDomain::<Fr>::new(n).unwrap();
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+        let evals = domain.fft(&coeffs);
+        for (k, eval) in evals.iter().enumerate() {
+            let point = domain.element(k);
+            let mut expected = Fr::zero();
+            let mut power = Fr::one();
+            for coeff in &coeffs {
+                expected += *coeff * power;
+                power *= point;
+            }
+            assert_eq!(*eval, expected, "mismatch at domain element {k}");
+        }
+    }
+}
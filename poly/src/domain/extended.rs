@@ -0,0 +1,191 @@
+//! An extended coset domain for dividing by the vanishing polynomial of a
+//! smaller domain in evaluation form, as used by the quotient computation
+//! `q(X) = f(X) / Z_H(X)` in PLONK- and Groth16-style SNARKs.
+//!
+//! Rather than interpolating `f` back to coefficients to divide by `Z_H`
+//! and then evaluating `q` again, `ExtendedDomain` evaluates `f` on a coset
+//! of a domain `N = n * c` that is large enough to hold `deg(q)`, divides
+//! pointwise, and inverse-transforms back. The division is made cheap by
+//! the fact that on the coset `ζ·⟨ω_N⟩`, `Z_H(X) = X^n − h^n` (`h` the
+//! base domain's coset offset, so `X^n − 1` for a plain subgroup) takes on
+//! only `c` distinct values (mirroring the trick used by the halo2 domain
+//! extension): since `ω_N^n` has order `c`,
+//! `Z_H(ζ ω_N^i) = ζ^n (ω_N^n)^i − h^n` cycles with period `c`, so those `c`
+//! values can be batch-inverted once at construction time.
+
+use crate::domain::{DomainCoeff, EvaluationDomain, Radix2EvaluationDomain};
+use ark_ff::{batch_inversion, FftField};
+use ark_std::vec::Vec;
+
+/// A coset domain of size `N = n * c`, extended from a base domain of size
+/// `n`, specialized for dividing evaluations by the vanishing polynomial of
+/// the base domain.
+#[derive(Clone, Debug)]
+pub struct ExtendedDomain<F: FftField> {
+    /// The original domain `H`, of size `n`.
+    base: Radix2EvaluationDomain<F>,
+    /// The extended domain, of size `N = n * extension_factor`, shifted by
+    /// `offset`.
+    extended: Radix2EvaluationDomain<F>,
+    /// `c = N / n`, the number of distinct values `Z_H` takes on the coset.
+    extension_factor: usize,
+    /// The coset offset `ζ`.
+    offset: F,
+    /// The inverse of the coset offset, `ζ^{-1}`.
+    offset_inv: F,
+    /// The batch-inverted values of `Z_H` on the coset, indexed by
+    /// `i % extension_factor`.
+    t_inv: Vec<F>,
+}
+
+impl<F: FftField> ExtendedDomain<F> {
+    /// Construct an extended domain from `base` that is large enough to
+    /// hold evaluations of a polynomial of degree `min_degree`, using
+    /// `offset` (a generator that is not in `base`) as the coset shift.
+    ///
+    /// Returns `None` if no power-of-two extension of `base` large enough
+    /// for `min_degree` exists for `F`, or if `offset` happens to lie in
+    /// `base` (in which case `Z_H` would vanish on part of the coset).
+    pub fn new(base: Radix2EvaluationDomain<F>, min_degree: usize, offset: F) -> Option<Self> {
+        let n = base.size();
+        let mut extension_factor = 1usize;
+        while n * extension_factor <= min_degree {
+            extension_factor = extension_factor.checked_mul(2)?;
+        }
+        let extended_size = n * extension_factor;
+
+        let extended_base = Radix2EvaluationDomain::new(extended_size)?;
+        let extended = extended_base.get_coset(offset)?;
+        if base.evaluate_vanishing_polynomial(offset).is_zero() {
+            return None;
+        }
+
+        // `ω_N^n` has order `extension_factor`, so `Z_H` evaluated at the
+        // coset elements cycles with period `extension_factor`. `Z_H(X) =
+        // X^n - base.coset_offset()^n`, not `X^n - 1`, since `base` may
+        // itself be a coset domain.
+        let small_root = extended.group_gen().pow([n as u64]);
+        let zeta_n = offset.pow([n as u64]);
+        let h_n = base.coset_offset_pow_size();
+        let mut t = Vec::with_capacity(extension_factor);
+        let mut cur = zeta_n;
+        for _ in 0..extension_factor {
+            t.push(cur - h_n);
+            cur *= small_root;
+        }
+        batch_inversion(&mut t);
+
+        Some(Self {
+            base,
+            extended,
+            extension_factor,
+            offset,
+            offset_inv: offset.inverse()?,
+            t_inv: t,
+        })
+    }
+
+    /// The base domain `H` this extended domain was built from.
+    pub fn base(&self) -> Radix2EvaluationDomain<F> {
+        self.base
+    }
+
+    /// The size of the extended domain, `N = n * c`.
+    pub fn size(&self) -> usize {
+        self.extended.size()
+    }
+
+    /// The coset offset `ζ` used to shift the extended domain off of `H`.
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    /// The inverse of the coset offset, `ζ^{-1}`.
+    pub fn offset_inv(&self) -> F {
+        self.offset_inv
+    }
+
+    /// Evaluate `coeffs` (given in coefficient form) on the coset, in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        self.extended.fft_in_place(coeffs)
+    }
+
+    /// Interpolate `evals` (given as evaluations on the coset) back to
+    /// coefficient form, in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        self.extended.ifft_in_place(evals)
+    }
+
+    /// Evaluate `coeffs` (given in coefficient form) on the coset.
+    pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.coset_fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Interpolate `evals` (given as evaluations on the coset) back to
+    /// coefficient form.
+    pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.coset_ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Divide `f_evals`, the evaluations of `f` on this coset, by `Z_H`,
+    /// in place, producing the evaluations of `q = f / Z_H` on the coset.
+    ///
+    /// `f_evals` must have length `self.size()` and must vanish on `H`'s
+    /// image under the division (i.e. `f` must actually be divisible by
+    /// `Z_H`); otherwise the result is simply `f(X) / Z_H(X)` evaluated
+    /// pointwise, which is meaningless as a polynomial unless that
+    /// divisibility holds.
+    pub fn divide_by_vanishing_polynomial_on_coset(&self, f_evals: &mut [F]) {
+        let c = self.extension_factor;
+        for (i, val) in f_evals.iter_mut().enumerate() {
+            *val *= self.t_inv[i % c];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    // `base` is itself a coset (non-unity offset) so this exercises the
+    // `Z_H(X) = X^n - base.coset_offset()^n` case, not just `X^n - 1`.
+    #[test]
+    fn divide_by_vanishing_polynomial_recovers_quotient() {
+        let rng = &mut test_rng();
+        let base = Radix2EvaluationDomain::<Fr>::new_coset(4, Fr::rand(rng)).unwrap();
+        let n = base.size();
+
+        let q_coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+        // Z_H(X) = X^n - h^n.
+        let h_n = base.coset_offset_pow_size();
+        let mut z_h_coeffs = vec![Fr::zero(); n + 1];
+        z_h_coeffs[0] = -h_n;
+        z_h_coeffs[n] = Fr::one();
+
+        // f = q * Z_H, via schoolbook convolution.
+        let mut f_coeffs = vec![Fr::zero(); q_coeffs.len() + z_h_coeffs.len() - 1];
+        for (i, a) in q_coeffs.iter().enumerate() {
+            for (j, b) in z_h_coeffs.iter().enumerate() {
+                f_coeffs[i + j] += *a * b;
+            }
+        }
+
+        let zeta = base.sample_element_outside_domain(rng);
+        let extended = ExtendedDomain::new(base, f_coeffs.len(), zeta).unwrap();
+
+        let mut f_evals = extended.coset_fft(&f_coeffs);
+        extended.divide_by_vanishing_polynomial_on_coset(&mut f_evals);
+        let mut q_recovered = extended.coset_ifft(&f_evals);
+        q_recovered.truncate(n);
+
+        assert_eq!(q_recovered, q_coeffs);
+    }
+}
@@ -0,0 +1,216 @@
+//! This module contains `GeneralEvaluationDomain`, an enum that can be a
+//! `Radix2EvaluationDomain`, a `MixedRadixEvaluationDomain`, or (when
+//! neither of those can hit the requested size) a
+//! `BluesteinEvaluationDomain`.
+//!
+//! `GeneralEvaluationDomain` picks the most efficient of the three so that
+//! callers do not have to reason about which concrete domain type is
+//! appropriate for a given size.
+
+use crate::domain::{
+    BluesteinEvaluationDomain, DomainCoeff, EvaluationDomain, MixedRadixEvaluationDomain,
+    Radix2EvaluationDomain,
+};
+use ark_ff::FftField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, rand::Rng, vec::Vec};
+
+/// A domain that picks the cheapest of `Radix2EvaluationDomain`,
+/// `MixedRadixEvaluationDomain`, and `BluesteinEvaluationDomain` that can
+/// represent the requested size: `Radix2EvaluationDomain::new` is tried
+/// first (a plain power-of-two domain), then
+/// `MixedRadixEvaluationDomain::new` (a power of two times the field's
+/// small odd cofactor), falling back to `BluesteinEvaluationDomain::new`
+/// (exactly `num_coeffs`, for any size neither smooth domain can reach) if
+/// both fail.
+///
+/// Note that this enum is `Clone` but not `Copy`, since the `Bluestein`
+/// variant carries heap-allocated precomputed tables.
+#[derive(Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub enum GeneralEvaluationDomain<F: FftField> {
+    /// A radix-2 domain.
+    Radix2(Radix2EvaluationDomain<F>),
+    /// A mixed-radix domain.
+    MixedRadix(MixedRadixEvaluationDomain<F>),
+    /// An exact-size domain built via Bluestein's chirp-z transform.
+    Bluestein(BluesteinEvaluationDomain<F>),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $method:ident $(, $args:expr)*) => {
+        match $self {
+            GeneralEvaluationDomain::Radix2(domain) => domain.$method($($args),*),
+            GeneralEvaluationDomain::MixedRadix(domain) => domain.$method($($args),*),
+            GeneralEvaluationDomain::Bluestein(domain) => domain.$method($($args),*),
+        }
+    };
+}
+
+impl<F: FftField> fmt::Debug for GeneralEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeneralEvaluationDomain::Radix2(domain) => domain.fmt(f),
+            GeneralEvaluationDomain::MixedRadix(domain) => domain.fmt(f),
+            GeneralEvaluationDomain::Bluestein(domain) => domain.fmt(f),
+        }
+    }
+}
+
+impl<F: FftField> EvaluationDomain<F> for GeneralEvaluationDomain<F> {
+    type Elements = GeneralElements<F>;
+
+    fn new(num_coeffs: usize) -> Option<Self> {
+        Radix2EvaluationDomain::new(num_coeffs)
+            .map(GeneralEvaluationDomain::Radix2)
+            .or_else(|| {
+                MixedRadixEvaluationDomain::new(num_coeffs)
+                    .map(GeneralEvaluationDomain::MixedRadix)
+            })
+            .or_else(|| {
+                BluesteinEvaluationDomain::new(num_coeffs).map(GeneralEvaluationDomain::Bluestein)
+            })
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        match self {
+            GeneralEvaluationDomain::Radix2(domain) => {
+                domain.get_coset(offset).map(GeneralEvaluationDomain::Radix2)
+            }
+            GeneralEvaluationDomain::MixedRadix(domain) => domain
+                .get_coset(offset)
+                .map(GeneralEvaluationDomain::MixedRadix),
+            GeneralEvaluationDomain::Bluestein(domain) => domain
+                .get_coset(offset)
+                .map(GeneralEvaluationDomain::Bluestein),
+        }
+    }
+
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        Radix2EvaluationDomain::<F>::compute_size_of_domain(num_coeffs)
+            .or_else(|| MixedRadixEvaluationDomain::<F>::compute_size_of_domain(num_coeffs))
+            .or_else(|| BluesteinEvaluationDomain::<F>::compute_size_of_domain(num_coeffs))
+    }
+
+    fn size(&self) -> usize {
+        dispatch!(self, size)
+    }
+
+    fn log_size_of_group(&self) -> u64 {
+        dispatch!(self, log_size_of_group)
+    }
+
+    fn size_inv(&self) -> F {
+        dispatch!(self, size_inv)
+    }
+
+    fn group_gen(&self) -> F {
+        dispatch!(self, group_gen)
+    }
+
+    fn group_gen_inv(&self) -> F {
+        dispatch!(self, group_gen_inv)
+    }
+
+    fn coset_offset(&self) -> F {
+        dispatch!(self, coset_offset)
+    }
+
+    fn coset_offset_inv(&self) -> F {
+        dispatch!(self, coset_offset_inv)
+    }
+
+    fn coset_offset_pow_size(&self) -> F {
+        dispatch!(self, coset_offset_pow_size)
+    }
+
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        dispatch!(self, fft_in_place, coeffs)
+    }
+
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        dispatch!(self, ifft_in_place, evals)
+    }
+
+    fn elements(&self) -> GeneralElements<F> {
+        match self {
+            GeneralEvaluationDomain::Radix2(domain) => {
+                GeneralElements(GeneralElementsInner::Radix2(domain.elements()))
+            }
+            GeneralEvaluationDomain::MixedRadix(domain) => {
+                GeneralElements(GeneralElementsInner::MixedRadix(domain.elements()))
+            }
+            GeneralEvaluationDomain::Bluestein(domain) => {
+                GeneralElements(GeneralElementsInner::Bluestein(domain.elements()))
+            }
+        }
+    }
+
+    fn sample_element_outside_domain<R: Rng>(&self, rng: &mut R) -> F {
+        dispatch!(self, sample_element_outside_domain, rng)
+    }
+}
+
+#[derive(Clone)]
+enum GeneralElementsInner<F: FftField> {
+    Radix2(<Radix2EvaluationDomain<F> as EvaluationDomain<F>>::Elements),
+    MixedRadix(<MixedRadixEvaluationDomain<F> as EvaluationDomain<F>>::Elements),
+    Bluestein(<BluesteinEvaluationDomain<F> as EvaluationDomain<F>>::Elements),
+}
+
+/// An iterator over the elements of a `GeneralEvaluationDomain`.
+#[derive(Clone)]
+pub struct GeneralElements<F: FftField>(GeneralElementsInner<F>);
+
+impl<F: FftField> Iterator for GeneralElements<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        match &mut self.0 {
+            GeneralElementsInner::Radix2(iter) => iter.next(),
+            GeneralElementsInner::MixedRadix(iter) => iter.next(),
+            GeneralElementsInner::Bluestein(iter) => iter.next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    // A power of two: `Radix2EvaluationDomain::new` succeeds directly.
+    #[test]
+    fn picks_radix2_for_a_power_of_two() {
+        let domain = GeneralEvaluationDomain::<Fr>::new(16).unwrap();
+        assert!(matches!(domain, GeneralEvaluationDomain::Radix2(_)));
+        assert_eq!(domain.size(), 16);
+    }
+
+    // Not a power of two, but within `Fr`'s two-adicity once rounded up:
+    // `Radix2EvaluationDomain::new` still succeeds, so it is picked before
+    // `MixedRadixEvaluationDomain::new` is tried.
+    #[test]
+    fn picks_radix2_when_it_can_round_up_the_size() {
+        let domain = GeneralEvaluationDomain::<Fr>::new(17).unwrap();
+        assert!(matches!(domain, GeneralEvaluationDomain::Radix2(_)));
+        assert_eq!(domain.size(), 32);
+    }
+
+    // Whichever variant is picked, `fft`/`ifft` must round-trip and
+    // `elements()` must agree with `element(i)` via the shared dispatch.
+    #[test]
+    fn fft_round_trips_and_elements_match_dispatch() {
+        for num_coeffs in [16, 17] {
+            let domain = GeneralEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            let rng = &mut test_rng();
+            let coeffs: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+
+            assert_eq!(domain.ifft(&domain.fft(&coeffs)), coeffs);
+
+            let expected: Vec<Fr> = (0..domain.size()).map(|i| domain.element(i)).collect();
+            let actual: Vec<Fr> = domain.elements().collect();
+            assert_eq!(actual, expected);
+        }
+    }
+}
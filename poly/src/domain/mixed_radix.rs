@@ -0,0 +1,398 @@
+//! This module contains `MixedRadixEvaluationDomain`, an `EvaluationDomain`
+//! for performing various kinds of polynomial arithmetic on top of fields
+//! that are fast-fourier-transform-friendly, but whose 2-adic subgroup is
+//! too small to hold the desired number of coefficients on its own.
+//!
+//! In pairing-based SNARKs, the scalar field's multiplicative group usually
+//! has order `2^s * t` for a small odd `t` (in addition to the usual large
+//! power of two). `MixedRadixEvaluationDomain` makes the additional factor
+//! `t` available, so domains of size `2^k * t` (for `k <= s`) can be formed
+//! without resorting to Bluestein's algorithm.
+//!
+//! The transform is a standard Cooley-Tukey mixed-radix decomposition:
+//! writing `n = n1 * cofactor` (`n1` the power-of-two part, `cofactor` the
+//! small odd part) and an index `i = cofactor * i1 + i2` (`i1 < n1`,
+//! `i2 < cofactor`), `X_k = sum_i x_i * root^(i * k)` splits into `cofactor`
+//! size-`n1` radix-2 DFTs (reusing the crate's existing power-of-two
+//! machinery), a per-element twiddle by `root^(i2 * k1)`, and `n1` size-
+//! `cofactor` schoolbook DFTs recombining the results — `O(n log n1 +
+//! n * cofactor)`, i.e. `O(n log n)` since `cofactor` is a fixed small
+//! constant for a given field.
+
+use crate::domain::{
+    precomputation::FftPrecomputation,
+    utils::{compute_powers_serial, radix2_fft_in_place_with_levels, radix2_fft_levels, Elements},
+    DomainCoeff, EvaluationDomain,
+};
+use ark_ff::{FftField, FftParameters};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, vec::Vec};
+
+/// Defines a domain over which finite field (I)FFTs can be performed. Works
+/// only for fields that have a large multiplicative subgroup of size that is
+/// a power-of-2 times a small `TWO_ADIC_COFACTOR`.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MixedRadixEvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// `log_2(self.size / self.cofactor)`.
+    pub log_size_of_group: u32,
+    /// The small odd cofactor dividing `self.size`.
+    pub cofactor: u64,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+    /// Offset that specifies the coset.
+    pub offset: F,
+    /// Inverse of the offset that specifies the coset.
+    pub offset_inv: F,
+    /// Constant coefficient for the vanishing polynomial.
+    /// Equals `self.offset^self.size`.
+    pub offset_pow_size: F,
+}
+
+impl<F: FftField> fmt::Debug for MixedRadixEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Mixed-radix multiplicative subgroup of size {}",
+            self.size
+        )
+    }
+}
+
+impl<F: FftField> MixedRadixEvaluationDomain<F> {
+    /// Build the twiddle tables a mixed-radix DFT of size `size = n1 *
+    /// cofactor` needs for primitive root `root`: the radix-2 levels for
+    /// the size-`n1` power-of-two part, followed (as the last entry) by
+    /// `[w2^0, w2^1, ..., w2^(cofactor - 1)]` for `w2 = root^n1`, used to
+    /// recombine the `n1` size-`cofactor` DFTs.
+    fn mixed_radix_levels(size: usize, cofactor: usize, root: F) -> Vec<Vec<F>> {
+        let n1 = size / cofactor;
+        let w1 = root.pow([cofactor as u64]);
+        let w2 = root.pow([n1 as u64]);
+        let mut levels = radix2_fft_levels(n1, w1);
+        levels.push(compute_powers_serial(cofactor, w2));
+        levels
+    }
+
+    /// A mixed-radix DFT of `a` (`a.len() = n1 * cofactor`), computing
+    /// `out[k] = sum_i a[i] * root^(i * k)` via `cofactor` radix-2 DFTs of
+    /// size `n1` and `n1` schoolbook DFTs of size `cofactor`, using the
+    /// twiddle tables `levels` from [`Self::mixed_radix_levels`] built for
+    /// the same `cofactor` and `root`.
+    fn mixed_radix_fft_in_place_with_levels<T: DomainCoeff<F>>(
+        a: &mut [T],
+        cofactor: usize,
+        root: F,
+        levels: &[Vec<F>],
+    ) {
+        let n = a.len();
+        let n1 = n / cofactor;
+        let n2 = cofactor;
+        let (radix2_levels, w2_powers) = levels.split_at(levels.len() - 1);
+        let w2_powers = &w2_powers[0];
+
+        // `cofactor` interleaved size-`n1` radix-2 DFTs, twiddled by
+        // `root^(i2 * k1)`.
+        let mut z = ark_std::vec![T::zero(); n];
+        for i2 in 0..n2 {
+            let mut sub: Vec<T> = (0..n1).map(|i1| a[n2 * i1 + i2]).collect();
+            radix2_fft_in_place_with_levels(&mut sub, radix2_levels);
+
+            let twiddle_step = root.pow([i2 as u64]);
+            let mut twiddle = F::one();
+            for (k1, val) in sub.into_iter().enumerate() {
+                let mut v = val;
+                v *= twiddle;
+                z[i2 * n1 + k1] = v;
+                twiddle *= twiddle_step;
+            }
+        }
+
+        // Recombine via `n1` schoolbook size-`cofactor` DFTs.
+        for k1 in 0..n1 {
+            for k2 in 0..n2 {
+                let mut acc = z[k1];
+                for i2 in 1..n2 {
+                    let mut term = z[i2 * n1 + k1];
+                    term *= w2_powers[(i2 * k2) % n2];
+                    acc += term;
+                }
+                a[k1 + n1 * k2] = acc;
+            }
+        }
+    }
+
+    pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        if !self.offset.is_one() {
+            Self::distribute_powers(coeffs, self.offset);
+        }
+        let levels = Self::mixed_radix_levels(self.size(), self.cofactor as usize, self.group_gen);
+        Self::mixed_radix_fft_in_place_with_levels(
+            coeffs,
+            self.cofactor as usize,
+            self.group_gen,
+            &levels,
+        );
+    }
+
+    pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        let levels =
+            Self::mixed_radix_levels(self.size(), self.cofactor as usize, self.group_gen_inv);
+        Self::mixed_radix_fft_in_place_with_levels(
+            evals,
+            self.cofactor as usize,
+            self.group_gen_inv,
+            &levels,
+        );
+        evals.iter_mut().for_each(|val| *val *= self.size_inv);
+        if !self.offset_inv.is_one() {
+            Self::distribute_powers(evals, self.offset_inv);
+        }
+    }
+
+    /// Build a [`FftPrecomputation`] holding the twiddle factors for both
+    /// directions of the transform over this domain, so that repeated
+    /// (I)FFTs over `self` don't need to regenerate them.
+    pub fn precompute(&self) -> FftPrecomputation<F> {
+        FftPrecomputation {
+            roots: Self::mixed_radix_levels(self.size(), self.cofactor as usize, self.group_gen),
+            inv_roots: Self::mixed_radix_levels(
+                self.size(),
+                self.cofactor as usize,
+                self.group_gen_inv,
+            ),
+            size_inv: self.size_inv,
+        }
+    }
+
+    /// Like [`EvaluationDomain::fft_in_place`], but consumes the twiddle
+    /// factors from `pc` instead of regenerating them.
+    pub fn fft_in_place_with_pc<T: DomainCoeff<F>>(
+        &self,
+        coeffs: &mut Vec<T>,
+        pc: &FftPrecomputation<F>,
+    ) {
+        coeffs.resize(self.size(), T::zero());
+        if !self.offset.is_one() {
+            Self::distribute_powers(&mut coeffs[..], self.offset);
+        }
+        Self::mixed_radix_fft_in_place_with_levels(
+            &mut coeffs[..],
+            self.cofactor as usize,
+            self.group_gen,
+            &pc.roots,
+        );
+    }
+
+    /// Like [`EvaluationDomain::ifft_in_place`], but consumes the twiddle
+    /// factors from `pc` instead of regenerating them.
+    pub fn ifft_in_place_with_pc<T: DomainCoeff<F>>(
+        &self,
+        evals: &mut Vec<T>,
+        pc: &FftPrecomputation<F>,
+    ) {
+        evals.resize(self.size(), T::zero());
+        Self::mixed_radix_fft_in_place_with_levels(
+            &mut evals[..],
+            self.cofactor as usize,
+            self.group_gen_inv,
+            &pc.inv_roots,
+        );
+        evals.iter_mut().for_each(|val| *val *= pc.size_inv);
+        if !self.offset_inv.is_one() {
+            Self::distribute_powers(&mut evals[..], self.offset_inv);
+        }
+    }
+
+    /// Like [`EvaluationDomain::lagrange_basis_from_monomial_basis`], but
+    /// consumes the twiddle factors from `pc` instead of regenerating them
+    /// — useful for a caller converting many bases over this same domain
+    /// (e.g. as an SRS grows) without re-deriving the twiddle scalars each
+    /// time.
+    pub fn lagrange_basis_from_monomial_basis_with_pc<G: DomainCoeff<F>>(
+        &self,
+        g_powers: &[G],
+        pc: &FftPrecomputation<F>,
+    ) -> Vec<G> {
+        let mut result = g_powers.to_vec();
+        self.ifft_in_place_with_pc(&mut result, pc);
+        result
+    }
+}
+
+impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
+    type Elements = Elements<F, Self>;
+
+    fn new(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_domain(num_coeffs)? as u64;
+        let log_size_of_group = (size / F::FftParams::SMALL_SUBGROUP_BASE? as u64).trailing_zeros();
+        let cofactor = F::FftParams::SMALL_SUBGROUP_BASE? as u64;
+
+        let group_gen = F::get_root_of_unity(size)?;
+        debug_assert_eq!(group_gen.pow([size]), F::one());
+        let size_as_field_element = F::from(size);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(MixedRadixEvaluationDomain {
+            size,
+            log_size_of_group,
+            cofactor,
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+        })
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(MixedRadixEvaluationDomain {
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size]),
+            ..*self
+        })
+    }
+
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let cofactor = F::FftParams::SMALL_SUBGROUP_BASE? as usize;
+        let mut size = cofactor;
+        while size < num_coeffs {
+            size *= 2;
+        }
+        if (size / cofactor).trailing_zeros() > F::FftParams::TWO_ADICITY {
+            None
+        } else {
+            Some(size)
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    fn log_size_of_group(&self) -> u64 {
+        self.log_size_of_group as u64
+    }
+
+    fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    fn group_gen_inv(&self) -> F {
+        self.group_gen_inv
+    }
+
+    fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    fn coset_offset_inv(&self) -> F {
+        self.offset_inv
+    }
+
+    fn coset_offset_pow_size(&self) -> F {
+        self.offset_pow_size
+    }
+
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        coeffs.resize(self.size(), T::zero());
+        self.in_order_fft_in_place(&mut coeffs[..]);
+    }
+
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        self.in_order_ifft_in_place(&mut evals[..]);
+    }
+
+    fn elements(&self) -> Elements<F, Self> {
+        Elements {
+            cur_elem: self.coset_offset(),
+            cur_pow: 0,
+            domain: *self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn ifft_of_fft_is_identity() {
+        // Rounds up to size 24 = 2^3 * 3 for BLS12-381's Fr, exercising the
+        // actual radix decomposition rather than a plain power of two.
+        let domain = MixedRadixEvaluationDomain::<Fr>::new(17).unwrap();
+        assert_eq!(domain.size(), 24);
+        assert_eq!(domain.cofactor, 3);
+
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+
+        assert_eq!(domain.ifft(&domain.fft(&coeffs)), coeffs);
+    }
+
+    #[test]
+    fn fft_matches_naive_dft_at_domain_elements() {
+        let domain = MixedRadixEvaluationDomain::<Fr>::new(17).unwrap();
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+
+        let evals = domain.fft(&coeffs);
+        for (k, eval) in evals.iter().enumerate() {
+            let point = domain.element(k);
+            let mut expected = Fr::zero();
+            let mut power = Fr::one();
+            for coeff in &coeffs {
+                expected += *coeff * power;
+                power *= point;
+            }
+            assert_eq!(*eval, expected, "mismatch at domain element {k}");
+        }
+    }
+
+    #[test]
+    fn fft_in_place_with_pc_matches_fft_in_place() {
+        let domain = MixedRadixEvaluationDomain::<Fr>::new(17).unwrap();
+        let pc = domain.precompute();
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+
+        let mut via_pc = coeffs.clone();
+        domain.fft_in_place_with_pc(&mut via_pc, &pc);
+        assert_eq!(via_pc, domain.fft(&coeffs));
+
+        let mut back_via_pc = via_pc.clone();
+        domain.ifft_in_place_with_pc(&mut back_via_pc, &pc);
+        assert_eq!(back_via_pc, coeffs);
+    }
+
+    #[test]
+    fn lagrange_basis_from_monomial_basis_with_pc_matches_default() {
+        let domain = MixedRadixEvaluationDomain::<Fr>::new(17).unwrap();
+        let pc = domain.precompute();
+        let rng = &mut test_rng();
+        let g_powers: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+
+        assert_eq!(
+            domain.lagrange_basis_from_monomial_basis_with_pc(&g_powers, &pc),
+            domain.lagrange_basis_from_monomial_basis(&g_powers),
+        );
+    }
+}
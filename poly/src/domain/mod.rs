@@ -14,21 +14,31 @@ use ark_std::{fmt, hash, rand::Rng, vec::Vec};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+pub mod bluestein;
+pub mod extended;
 pub mod general;
 pub mod mixed_radix;
+pub mod precomputation;
 pub mod radix2;
 pub(crate) mod utils;
 
+pub use bluestein::BluesteinEvaluationDomain;
+pub use extended::ExtendedDomain;
 pub use general::GeneralEvaluationDomain;
 pub use mixed_radix::MixedRadixEvaluationDomain;
+pub use precomputation::FftPrecomputation;
 pub use radix2::Radix2EvaluationDomain;
 
 /// Defines a domain over which finite field (I)FFTs can be performed. The
 /// size of the supported FFT depends on the size of the multiplicative
 /// subgroup. For efficiency, we recommend that the field has at least one large
 /// subgroup generated by a root of unity.
+///
+/// Note that implementors are only required to be `Clone`, not `Copy`:
+/// domains whose construction is not O(1) (e.g. `BluesteinEvaluationDomain`,
+/// which caches heap-allocated chirp and FFT tables) cannot be `Copy`.
 pub trait EvaluationDomain<F: FftField>:
-    Copy + Clone + hash::Hash + Eq + PartialEq + fmt::Debug + CanonicalSerialize + CanonicalDeserialize
+    Clone + hash::Hash + Eq + PartialEq + fmt::Debug + CanonicalSerialize + CanonicalDeserialize
 {
     /// The type of the elements iterator.
     type Elements: Iterator<Item = F> + Sized;
@@ -220,6 +230,62 @@ pub trait EvaluationDomain<F: FftField>:
         }
     }
 
+    /// Evaluate the unique interpolant of `evals` (the evaluations, in
+    /// order, of some polynomial of degree `< |domain|` over `self`) at an
+    /// arbitrary point `tau`, in time O(|domain|).
+    ///
+    /// This uses the barycentric formula specialized to a coset subgroup,
+    /// rather than recovering the polynomial's coefficients via an IFFT:
+    /// with `H` the coset (generator `g`, offset `h`, size `m`) and
+    /// `Z_H(x) = x^m - h^m` its vanishing polynomial, the barycentric
+    /// weights are `w_i = 1 / (m * (h g^i)^{m - 1})`, and
+    /// `P(tau) = Z_H(tau) * sum_i evals[i] * w_i / (tau - h g^i)`.
+    /// As in `evaluate_all_lagrange_coefficients`, `w_{i+1} = g * w_i`, so
+    /// it is only computed once.
+    fn evaluate_interpolant(&self, evals: &[F], tau: F) -> F {
+        assert_eq!(evals.len(), self.size());
+        let size = self.size();
+        let z_h_at_tau = self.evaluate_vanishing_polynomial(tau);
+        let offset = self.coset_offset();
+        let group_gen = self.group_gen();
+
+        if z_h_at_tau.is_zero() {
+            // tau = h g^i for some i; the interpolant agrees with evals[i]
+            // exactly at that node, so we can skip the barycentric sum
+            // entirely and just locate i by brute force.
+            let mut omega_i = offset;
+            for &eval in evals {
+                if omega_i == tau {
+                    return eval;
+                }
+                omega_i *= &group_gen;
+            }
+            unreachable!("evaluate_vanishing_polynomial(tau) == 0 implies tau is a domain element");
+        }
+
+        use ark_ff::fields::batch_inversion;
+
+        // w_0 = 1 / (m * h^(m-1))
+        let mut w_i = (self.size_as_field_element() * offset.pow([size as u64 - 1]))
+            .inverse()
+            .unwrap();
+
+        let mut denom = vec![F::zero(); size];
+        let mut cur_elem = offset;
+        for d in &mut denom {
+            *d = tau - cur_elem;
+            cur_elem *= &group_gen;
+        }
+        batch_inversion(&mut denom);
+
+        let mut result = F::zero();
+        for (eval, inv_denom) in evals.iter().zip(&denom) {
+            result += *eval * w_i * inv_denom;
+            w_i *= &group_gen;
+        }
+        result * z_h_at_tau
+    }
+
     /// Return the sparse vanishing polynomial.
     fn vanishing_polynomial(&self) -> crate::univariate::SparsePolynomial<F> {
         let constant_coeff = self.coset_offset_pow_size();
@@ -290,6 +356,31 @@ pub trait EvaluationDomain<F: FftField>:
 
         result
     }
+
+    /// Convert `g_powers` (e.g. the monomial-basis group elements of an
+    /// SRS) into the Lagrange basis defined by this domain, via a single
+    /// IFFT.
+    ///
+    /// `DomainCoeff`'s bound (`Add`/`Sub`/`MulAssign<F>`/`Zero`) admits
+    /// curve-group elements, so this compiles for any `G` that implements
+    /// it; the IFFT's additions become group additions and its
+    /// `MulAssign<F>`s become scalar multiplications, each twiddle scalar
+    /// shared (and so only derived once) across every block of its stage.
+    /// This crate cannot depend on `ark-ec`, though, so it has no way to
+    /// specialize those scalar multiplications for a particular curve
+    /// (e.g. further batching via a windowed/Pippenger-style MSM); callers
+    /// who need that should implement this conversion directly against
+    /// their curve's scalar-multiplication API instead of going through
+    /// this default.
+    ///
+    /// A caller converting many `g_powers` bases over the *same* domain
+    /// (e.g. repeatedly as an SRS grows) can skip regenerating the twiddle
+    /// scalars on every call by building a `FftPrecomputation` once and
+    /// using `Radix2EvaluationDomain::lagrange_basis_from_monomial_basis_with_pc`
+    /// or `MixedRadixEvaluationDomain`'s equivalent instead.
+    fn lagrange_basis_from_monomial_basis<G: DomainCoeff<F>>(&self, g_powers: &[G]) -> Vec<G> {
+        self.ifft(g_powers)
+    }
 }
 
 /// Types that can be FFT-ed must implement this trait.
@@ -324,3 +415,55 @@ where
         + PartialEq,
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Radix2EvaluationDomain;
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn evaluate_interpolant_matches_naive_evaluation_out_of_domain() {
+        let size = 8;
+        let rng = &mut test_rng();
+        let offset = Fr::rand(rng);
+        let domain = Radix2EvaluationDomain::<Fr>::new_coset(size, offset).unwrap();
+
+        let coeffs: Vec<Fr> = (0..size).map(|_| Fr::rand(rng)).collect();
+        let evals = domain.fft(&coeffs);
+
+        // `tau` is sampled outside the coset, so this exercises the
+        // barycentric-sum branch rather than the in-domain shortcut.
+        let tau = domain.sample_element_outside_domain(rng);
+
+        let mut naive = Fr::from(0u64);
+        let mut power = Fr::from(1u64);
+        for coeff in &coeffs {
+            naive += *coeff * power;
+            power *= tau;
+        }
+
+        assert_eq!(domain.evaluate_interpolant(&evals, tau), naive);
+    }
+
+    #[test]
+    fn evaluate_interpolant_matches_lagrange_coefficients_out_of_domain() {
+        let size = 8;
+        let rng = &mut test_rng();
+        let offset = Fr::rand(rng);
+        let domain = Radix2EvaluationDomain::<Fr>::new_coset(size, offset).unwrap();
+
+        let evals: Vec<Fr> = (0..size).map(|_| Fr::rand(rng)).collect();
+        let tau = domain.sample_element_outside_domain(rng);
+
+        let lagrange_coeffs = domain.evaluate_all_lagrange_coefficients(tau);
+        let expected: Fr = evals
+            .iter()
+            .zip(&lagrange_coeffs)
+            .map(|(eval, l_i)| *eval * l_i)
+            .sum();
+
+        assert_eq!(domain.evaluate_interpolant(&evals, tau), expected);
+    }
+}
@@ -0,0 +1,57 @@
+//! A cache of FFT twiddle factors for repeated transforms over the same
+//! domain.
+//!
+//! Every call to `fft_in_place`/`ifft_in_place` recomputes the powers of
+//! `group_gen` (and `group_gen_inv`) needed by each butterfly stage from
+//! scratch. A prover that runs many transforms over one domain can instead
+//! build an `FftPrecomputation` once via `domain.precompute()` and reuse it
+//! across calls, the same way the cached `omega`/`omegainv`/`size_inv`
+//! fields are reused by bellman- and kzg-style domains.
+
+use ark_ff::FftField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::vec::Vec;
+
+/// Precomputed twiddle factors for repeated (I)FFTs over a single
+/// `Radix2EvaluationDomain` or `MixedRadixEvaluationDomain`.
+///
+/// `roots` holds, for each stage of the transform (in order of increasing
+/// stage size), the powers of the root of unity that stage needs;
+/// `inv_roots` holds the analogous table built from the inverse generator,
+/// and `size_inv` is `1 / n`. A `Radix2EvaluationDomain`'s transform has
+/// `log_2(n)` butterfly stages; a `MixedRadixEvaluationDomain`'s has those
+/// same butterfly stages for its power-of-two part, followed by one more
+/// entry for the small-cofactor combining step of its mixed-radix
+/// decomposition.
+///
+/// Serializable so a verifier (or anyone else amortizing many transforms
+/// over one domain) can build it once and load it thereafter instead of
+/// regenerating the twiddle tables on every run.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FftPrecomputation<F: FftField> {
+    pub(crate) roots: Vec<Vec<F>>,
+    pub(crate) inv_roots: Vec<Vec<F>>,
+    pub(crate) size_inv: F,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{EvaluationDomain, Radix2EvaluationDomain};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn serialize_deserialize_round_trips() {
+        let domain = Radix2EvaluationDomain::<Fr>::new(16).unwrap();
+        let pc = domain.precompute();
+
+        let mut bytes = Vec::new();
+        pc.serialize_compressed(&mut bytes).unwrap();
+        let deserialized = FftPrecomputation::<Fr>::deserialize_compressed(&bytes[..]).unwrap();
+
+        assert_eq!(pc.roots, deserialized.roots);
+        assert_eq!(pc.inv_roots, deserialized.inv_roots);
+        assert_eq!(pc.size_inv, deserialized.size_inv);
+    }
+}
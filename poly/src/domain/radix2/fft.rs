@@ -0,0 +1,138 @@
+//! The serial FFT/IFFT butterfly network used by `Radix2EvaluationDomain`.
+
+use crate::domain::{
+    precomputation::FftPrecomputation,
+    utils::{radix2_fft_in_place_with_levels, radix2_fft_levels},
+    DomainCoeff, EvaluationDomain,
+};
+use ark_ff::FftField;
+use ark_std::vec::Vec;
+
+use super::Radix2EvaluationDomain;
+
+impl<F: FftField> Radix2EvaluationDomain<F> {
+    /// Apply a forward FFT to `coeffs`, which are assumed to already be
+    /// zero-padded to `self.size()`. The result is in the natural
+    /// (non-bit-reversed) order.
+    pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T]) {
+        if !self.offset.is_one() {
+            Self::distribute_powers(coeffs, self.offset);
+        }
+        let levels = radix2_fft_levels(coeffs.len(), self.group_gen);
+        radix2_fft_in_place_with_levels(coeffs, &levels);
+    }
+
+    /// Apply an inverse FFT to `evals`, which are assumed to already be
+    /// zero-padded to `self.size()`.
+    pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T]) {
+        let levels = radix2_fft_levels(evals.len(), self.group_gen_inv);
+        radix2_fft_in_place_with_levels(evals, &levels);
+        evals.iter_mut().for_each(|val| *val *= self.size_inv);
+        if !self.offset_inv.is_one() {
+            Self::distribute_powers(evals, self.offset_inv);
+        }
+    }
+
+    /// Build a [`FftPrecomputation`] holding the twiddle factors for both
+    /// directions of the transform over this domain, so that repeated
+    /// (I)FFTs over `self` don't need to regenerate them.
+    pub fn precompute(&self) -> FftPrecomputation<F> {
+        FftPrecomputation {
+            roots: radix2_fft_levels(self.size(), self.group_gen),
+            inv_roots: radix2_fft_levels(self.size(), self.group_gen_inv),
+            size_inv: self.size_inv,
+        }
+    }
+
+    /// Like [`EvaluationDomain::fft_in_place`], but consumes the twiddle
+    /// factors from `pc` instead of regenerating them.
+    pub fn fft_in_place_with_pc<T: DomainCoeff<F>>(
+        &self,
+        coeffs: &mut Vec<T>,
+        pc: &FftPrecomputation<F>,
+    ) {
+        coeffs.resize(self.size(), T::zero());
+        if !self.offset.is_one() {
+            Self::distribute_powers(&mut coeffs[..], self.offset);
+        }
+        radix2_fft_in_place_with_levels(&mut coeffs[..], &pc.roots);
+    }
+
+    /// Like [`EvaluationDomain::ifft_in_place`], but consumes the twiddle
+    /// factors from `pc` instead of regenerating them.
+    pub fn ifft_in_place_with_pc<T: DomainCoeff<F>>(
+        &self,
+        evals: &mut Vec<T>,
+        pc: &FftPrecomputation<F>,
+    ) {
+        evals.resize(self.size(), T::zero());
+        radix2_fft_in_place_with_levels(&mut evals[..], &pc.inv_roots);
+        evals.iter_mut().for_each(|val| *val *= pc.size_inv);
+        if !self.offset_inv.is_one() {
+            Self::distribute_powers(&mut evals[..], self.offset_inv);
+        }
+    }
+
+    /// Like [`EvaluationDomain::lagrange_basis_from_monomial_basis`], but
+    /// consumes the twiddle factors from `pc` instead of regenerating them
+    /// — useful for a caller converting many bases over this same domain
+    /// (e.g. as an SRS grows) without re-deriving the twiddle scalars each
+    /// time.
+    pub fn lagrange_basis_from_monomial_basis_with_pc<G: DomainCoeff<F>>(
+        &self,
+        g_powers: &[G],
+        pc: &FftPrecomputation<F>,
+    ) -> Vec<G> {
+        let mut result = g_powers.to_vec();
+        self.ifft_in_place_with_pc(&mut result, pc);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn ifft_of_fft_is_identity() {
+        let n = 16;
+        let domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+        assert_eq!(domain.ifft(&domain.fft(&coeffs)), coeffs);
+    }
+
+    #[test]
+    fn fft_in_place_with_pc_matches_fft_in_place() {
+        let n = 16;
+        let domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+        let pc = domain.precompute();
+        let rng = &mut test_rng();
+        let coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+        let mut via_pc = coeffs.clone();
+        domain.fft_in_place_with_pc(&mut via_pc, &pc);
+        assert_eq!(via_pc, domain.fft(&coeffs));
+
+        let mut back_via_pc = via_pc.clone();
+        domain.ifft_in_place_with_pc(&mut back_via_pc, &pc);
+        assert_eq!(back_via_pc, coeffs);
+    }
+
+    #[test]
+    fn lagrange_basis_from_monomial_basis_with_pc_matches_default() {
+        let n = 16;
+        let domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+        let pc = domain.precompute();
+        let rng = &mut test_rng();
+        let g_powers: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+        assert_eq!(
+            domain.lagrange_basis_from_monomial_basis_with_pc(&g_powers, &pc),
+            domain.lagrange_basis_from_monomial_basis(&g_powers),
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! This module contains `Radix2EvaluationDomain`, an `EvaluationDomain`
+//! for performing various kinds of polynomial arithmetic on top of a
+//! domain of size that is a power of 2.
+//!
+//! `Radix2EvaluationDomain` is the most common case, and is the fastest
+//! domain available whenever the fft field has enough two-adicity to
+//! support it. `MixedRadixEvaluationDomain` should be used when the
+//! desired size is a power of 2 times the field's small odd cofactor.
+
+use crate::domain::{utils::Elements, DomainCoeff, EvaluationDomain};
+use ark_ff::{FftField, FftParameters};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, vec::Vec};
+
+mod fft;
+
+/// Defines a domain over which finite field (I)FFTs can be performed.
+/// Works only for fields that have a large multiplicative subgroup
+/// of size that is a power-of-2.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Radix2EvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// `log_2(self.size)`.
+    pub log_size_of_group: u32,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+    /// Offset that specifies the coset.
+    pub offset: F,
+    /// Inverse of the offset that specifies the coset.
+    pub offset_inv: F,
+    /// Constant coefficient for the vanishing polynomial.
+    /// Equals `self.offset^self.size`.
+    pub offset_pow_size: F,
+}
+
+impl<F: FftField> fmt::Debug for Radix2EvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Radix-2 multiplicative subgroup of size {}", self.size)
+    }
+}
+
+impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
+    type Elements = Elements<F, Self>;
+
+    fn new(num_coeffs: usize) -> Option<Self> {
+        let size = num_coeffs.checked_next_power_of_two()? as u64;
+        let log_size_of_group = size.trailing_zeros();
+
+        if log_size_of_group > F::FftParams::TWO_ADICITY {
+            return None;
+        }
+
+        let group_gen = F::get_root_of_unity(size)?;
+        // Check that it is indeed the 2^(log_size_of_group) root of unity.
+        debug_assert_eq!(group_gen.pow([size]), F::one());
+        let size_as_field_element = F::from(size);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(Radix2EvaluationDomain {
+            size,
+            log_size_of_group,
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+        })
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(Radix2EvaluationDomain {
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size]),
+            ..*self
+        })
+    }
+
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let size = num_coeffs.checked_next_power_of_two()?;
+        if size.trailing_zeros() > F::FftParams::TWO_ADICITY {
+            None
+        } else {
+            Some(size)
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    fn log_size_of_group(&self) -> u64 {
+        self.log_size_of_group as u64
+    }
+
+    fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    fn group_gen_inv(&self) -> F {
+        self.group_gen_inv
+    }
+
+    fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    fn coset_offset_inv(&self) -> F {
+        self.offset_inv
+    }
+
+    fn coset_offset_pow_size(&self) -> F {
+        self.offset_pow_size
+    }
+
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        coeffs.resize(self.size(), T::zero());
+        self.in_order_fft_in_place(&mut coeffs[..]);
+    }
+
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        self.in_order_ifft_in_place(&mut evals[..]);
+    }
+
+    fn elements(&self) -> Elements<F, Self> {
+        Elements {
+            cur_elem: self.coset_offset(),
+            cur_pow: 0,
+            domain: *self,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Utilities for computing domain elements and indices, and the serial
+//! radix-2 FFT core, shared across the different `EvaluationDomain`
+//! implementations (`Radix2EvaluationDomain` uses it directly;
+//! `MixedRadixEvaluationDomain` uses it for the power-of-two part of its
+//! mixed-radix decomposition).
+
+use crate::domain::{DomainCoeff, EvaluationDomain};
+use ark_ff::FftField;
+use ark_std::vec::Vec;
+
+/// An iterator over the elements of an evaluation domain.
+#[derive(Clone)]
+pub struct Elements<F: FftField, D: EvaluationDomain<F>> {
+    pub(crate) cur_elem: F,
+    pub(crate) cur_pow: u64,
+    pub(crate) domain: D,
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> Iterator for Elements<F, D> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        if self.cur_pow == self.domain.size() as u64 {
+            None
+        } else {
+            let cur_elem = self.cur_elem;
+            self.cur_elem *= self.domain.group_gen();
+            self.cur_pow += 1;
+            Some(cur_elem)
+        }
+    }
+}
+
+/// Reverse the lowest `log_len` bits of `n`.
+#[inline]
+pub(crate) fn bitrev(n: u64, log_len: u32) -> u64 {
+    n.reverse_bits() >> (64 - log_len)
+}
+
+/// Permute `v` in place into bit-reversed order, assuming `v.len()` is a
+/// power of two and `log_len = log2(v.len())`.
+pub(crate) fn bitrev_permute<T>(v: &mut [T], log_len: u32) {
+    let n = v.len() as u64;
+    for k in 0..n {
+        let rk = bitrev(k, log_len);
+        if k < rk {
+            v.swap(k as usize, rk as usize);
+        }
+    }
+}
+
+/// Compute `[1, root, root^2, ..., root^(size - 1)]`.
+pub(crate) fn compute_powers_serial<F: FftField>(size: usize, root: F) -> Vec<F> {
+    let mut value = F::one();
+    let mut powers = Vec::with_capacity(size);
+    for _ in 0..size {
+        powers.push(value);
+        value *= root;
+    }
+    powers
+}
+
+/// For each butterfly stage of an unshifted, size-`n` (a power of two)
+/// radix-2 DFT using primitive root `root`, compute the powers of `root`
+/// that stage needs (in order of increasing stage size).
+pub(crate) fn radix2_fft_levels<F: FftField>(n: usize, root: F) -> Vec<Vec<F>> {
+    if n <= 1 {
+        return Vec::new();
+    }
+    let mut levels = Vec::with_capacity(n.trailing_zeros() as usize);
+    let mut len = 2;
+    while len <= n {
+        let half_len = len / 2;
+        // `root^(n / len)` generates the subgroup of order `len`.
+        let step = root.pow([(n / len) as u64]);
+        levels.push(compute_powers_serial(half_len, step));
+        len *= 2;
+    }
+    levels
+}
+
+/// A decimation-in-time radix-2 DFT that consumes naturally-ordered input
+/// and produces naturally-ordered output `out[k] = sum_i a[i] * root^(i *
+/// k)`, implemented by permuting into bit-reversed order and running the
+/// standard butterfly network using the twiddle factors in `levels` (one
+/// entry per stage, smallest stage first, as produced by
+/// [`radix2_fft_levels`]).
+pub(crate) fn radix2_fft_in_place_with_levels<F: FftField, T: DomainCoeff<F>>(
+    a: &mut [T],
+    levels: &[Vec<F>],
+) {
+    let n = a.len();
+    if n <= 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+    bitrev_permute(a, log_n);
+
+    let mut len = 2;
+    for twiddles in levels {
+        let half_len = len / 2;
+        for block in a.chunks_mut(len) {
+            for i in 0..half_len {
+                let u = block[i];
+                let mut v = block[i + half_len];
+                v *= twiddles[i];
+                block[i] = u + v;
+                block[i + half_len] = u - v;
+            }
+        }
+        len *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{EvaluationDomain, Radix2EvaluationDomain};
+    use ark_ff::{One, Zero};
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    // `elements()` must agree with `element(i)` (which includes the coset
+    // offset) at every index, not just for plain subgroup domains.
+    #[test]
+    fn elements_matches_element_on_a_coset() {
+        let rng = &mut test_rng();
+        let domain = Radix2EvaluationDomain::<Fr>::new_coset(8, Fr::rand(rng)).unwrap();
+
+        let expected: Vec<Fr> = (0..domain.size()).map(|i| domain.element(i)).collect();
+        let actual: Vec<Fr> = domain.elements().collect();
+        assert_eq!(actual, expected);
+    }
+
+    // Cross-check the shared radix-2 core (used directly by
+    // `Radix2EvaluationDomain` and for the power-of-two part of
+    // `MixedRadixEvaluationDomain`'s decomposition) against a naive DFT.
+    #[test]
+    fn radix2_fft_matches_naive_dft() {
+        let n = 16;
+        let root = Fr::get_root_of_unity(n as u64).unwrap();
+        let levels = radix2_fft_levels(n, root);
+
+        let rng = &mut test_rng();
+        let a: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+        let mut out = a.clone();
+        radix2_fft_in_place_with_levels(&mut out, &levels);
+
+        for (k, out_k) in out.iter().enumerate() {
+            let point = root.pow([k as u64]);
+            let mut expected = Fr::zero();
+            let mut power = Fr::one();
+            for coeff in &a {
+                expected += *coeff * power;
+                power *= point;
+            }
+            assert_eq!(*out_k, expected, "mismatch at index {k}");
+        }
+    }
+}